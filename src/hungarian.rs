@@ -0,0 +1,165 @@
+//! Kuhn-Munkres (Hungarian) algorithm for optimal one-to-one assignment over a square cost
+//! matrix. Backs [`Assessment::assign`](crate::Assessment::assign).
+
+/// Solve the assignment problem for the square `cost` matrix, returning `result[row]` = the
+/// column assigned to that row. `cost` must be square; pad with dummy rows/columns beforehand.
+///
+/// Implements the standard Munkres steps: row/column reduction, covering all zeros with the
+/// minimum number of lines, and - if fewer than `n` lines are needed - adjusting uncovered
+/// values and repeating until a complete zero assignment exists. `O(n^4)` in the worst case:
+/// each of up to `n` augmentations may take `O(n)` matrix scans and adjustments of `O(n^2)` each.
+pub(crate) fn solve(cost: &[Vec<f64>]) -> Vec<usize> {
+    let n = cost.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    debug_assert!(cost.iter().all(|row| row.len() == n), "cost matrix must be square");
+
+    let mut c = cost.to_vec();
+
+    // Step 0/1: subtract each row's, then each column's, minimum value.
+    for row in c.iter_mut() {
+        let min = row.iter().copied().fold(f64::INFINITY, f64::min);
+        for v in row.iter_mut() {
+            *v -= min;
+        }
+    }
+    for col in 0..n {
+        let min = c.iter().map(|row| row[col]).fold(f64::INFINITY, f64::min);
+        for row in c.iter_mut() {
+            row[col] -= min;
+        }
+    }
+
+    // 0 = unmarked, 1 = starred, 2 = primed.
+    let mut mark = vec![vec![0u8; n]; n];
+    let mut row_covered = vec![false; n];
+    let mut col_covered = vec![false; n];
+
+    // Star an arbitrary zero in each row/column that has none starred yet.
+    for row in 0..n {
+        for col in 0..n {
+            if is_zero(c[row][col]) && !row_covered[row] && !col_covered[col] {
+                mark[row][col] = 1;
+                row_covered[row] = true;
+                col_covered[col] = true;
+            }
+        }
+    }
+    row_covered.fill(false);
+    col_covered.fill(false);
+
+    loop {
+        // Cover every column containing a starred zero.
+        for col in 0..n {
+            col_covered[col] = (0..n).any(|row| mark[row][col] == 1);
+        }
+        if col_covered.iter().filter(|&&covered| covered).count() == n {
+            break;
+        }
+
+        'primed: loop {
+            let Some((row, col)) = find_uncovered_zero(&c, &row_covered, &col_covered) else {
+                // No uncovered zero remains: adjust the matrix and keep priming.
+                adjust_uncovered(&mut c, &row_covered, &col_covered);
+                continue 'primed;
+            };
+            mark[row][col] = 2;
+
+            if let Some(starred_col) = (0..n).find(|&col| mark[row][col] == 1) {
+                row_covered[row] = true;
+                col_covered[starred_col] = false;
+            } else {
+                augment_path(&mut mark, row, col, n);
+                row_covered.fill(false);
+                col_covered.fill(false);
+                for r in mark.iter_mut() {
+                    for m in r.iter_mut() {
+                        if *m == 2 {
+                            *m = 0;
+                        }
+                    }
+                }
+                break 'primed;
+            }
+        }
+    }
+
+    (0..n)
+        .map(|row| (0..n).find(|&col| mark[row][col] == 1).expect("every row has a starred zero"))
+        .collect()
+}
+
+fn is_zero(v: f64) -> bool {
+    v.abs() < 1e-9
+}
+
+fn find_uncovered_zero(
+    c: &[Vec<f64>],
+    row_covered: &[bool],
+    col_covered: &[bool],
+) -> Option<(usize, usize)> {
+    let n = c.len();
+    for row in 0..n {
+        if row_covered[row] {
+            continue;
+        }
+        for col in 0..n {
+            if !col_covered[col] && is_zero(c[row][col]) {
+                return Some((row, col));
+            }
+        }
+    }
+    None
+}
+
+/// Find the smallest value not covered by a line, subtract it from every uncovered entry and
+/// add it to every doubly-covered entry.
+fn adjust_uncovered(c: &mut [Vec<f64>], row_covered: &[bool], col_covered: &[bool]) {
+    let n = c.len();
+    let mut min = f64::INFINITY;
+    for row in 0..n {
+        if row_covered[row] {
+            continue;
+        }
+        for col in 0..n {
+            if !col_covered[col] && c[row][col] < min {
+                min = c[row][col];
+            }
+        }
+    }
+    for row in 0..n {
+        for col in 0..n {
+            if !row_covered[row] && !col_covered[col] {
+                c[row][col] -= min;
+            } else if row_covered[row] && col_covered[col] {
+                c[row][col] += min;
+            }
+        }
+    }
+}
+
+/// Build the alternating path starting at the primed zero `(row, col)`: a starred zero in the
+/// same column (if any), then a primed zero in that starred zero's row, and so on until a
+/// column has no starred zero. Flip every primed zero on the path to starred, and every
+/// starred zero on the path to unmarked - this grows the matching by one.
+fn augment_path(mark: &mut [Vec<u8>], row: usize, col: usize, n: usize) {
+    let mut path = vec![(row, col)];
+    loop {
+        let (_, col) = *path.last().unwrap();
+        let Some(starred_row) = (0..n).find(|&r| mark[r][col] == 1) else {
+            break;
+        };
+        path.push((starred_row, col));
+
+        let (row, _) = *path.last().unwrap();
+        let primed_col = (0..n)
+            .find(|&c| mark[row][c] == 2)
+            .expect("every starred zero following a prime has a paired prime");
+        path.push((row, primed_col));
+    }
+
+    for &(r, c) in &path {
+        mark[r][c] = if mark[r][c] == 1 { 0 } else { 1 };
+    }
+}
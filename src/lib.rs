@@ -1,5 +1,10 @@
 use std::borrow::Cow;
 
+mod hungarian;
+mod similarity;
+
+pub use similarity::Similarity;
+
 /// A kind of column.
 pub trait ColumnKind {
     /// Assess how much given header is similar to what we can expect for this kind of column.
@@ -57,6 +62,159 @@ impl Assessment {
     {
         rows.map(move |row| Assessment::for_headers(column_set, row))
     }
+
+    /// Resolve the headers x column-kinds similarity `matrix` (as produced by
+    /// [`for_headers`](Self::for_headers)) into the one-to-one assignment of kinds to headers
+    /// that maximizes total similarity, via the Hungarian algorithm. Pairings at or below
+    /// `threshold` are dropped as unassigned. `O(n^4)` in the worst case, for `n` =
+    /// `max(headers, kinds)`.
+    pub fn assign(matrix: &[Vec<Self>], threshold: f32) -> Vec<ColumnAssignment> {
+        let headers = matrix.len();
+        let kinds = matrix.first().map_or(0, |row| row.len());
+        if headers == 0 || kinds == 0 {
+            return Vec::new();
+        }
+
+        // cost[kind_index][header_position], padded to square with dummy (similarity 0) entries.
+        let n = headers.max(kinds);
+        let cost: Vec<Vec<f64>> = (0..n)
+            .map(|kind_index| {
+                (0..n)
+                    .map(|header_position| {
+                        let similarity = matrix
+                            .get(header_position)
+                            .and_then(|row| row.get(kind_index))
+                            .map_or(0.0, |a| a.similarity);
+                        1.0 - similarity as f64
+                    })
+                    .collect()
+            })
+            .collect();
+
+        hungarian::solve(&cost)
+            .into_iter()
+            .enumerate()
+            .filter_map(|(kind_index, header_position)| {
+                if kind_index >= kinds || header_position >= headers {
+                    // Paired with a padding row/column: no real kind or header on the other end.
+                    return None;
+                }
+                let similarity = matrix[header_position][kind_index].similarity;
+                (similarity > threshold).then_some(ColumnAssignment {
+                    kind_index,
+                    header_position,
+                    similarity,
+                })
+            })
+            .collect()
+    }
+}
+
+/// A single kind-to-header pairing produced by [`Assessment::assign`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColumnAssignment {
+    /// Index into the `column_set` passed to [`Assessment::for_headers`].
+    pub kind_index: usize,
+    /// Position of the assigned header, as in [`Assessment::position`].
+    pub header_position: usize,
+    pub similarity: f32,
+}
+
+impl Assessment {
+    /// Classify a single column from sampled cell `values` alone, via the trimmed mean of each
+    /// kind's [`assess_value`](ColumnKind::assess_value) score across non-blank samples.
+    ///
+    /// Returns `None` if fewer than `min_samples` non-blank values were supplied. `position` on
+    /// the returned assessments is always 0; callers tracking several columns should record the
+    /// position themselves (see [`classify_columns_by_value`](Self::classify_columns_by_value)).
+    pub fn for_column_values<'v>(
+        column_set: &[impl ColumnKind],
+        values: impl Iterator<Item = &'v str>,
+        min_samples: usize,
+    ) -> Option<Vec<Self>> {
+        let samples: Vec<&str> = values.filter(|v| !v.trim().is_empty()).collect();
+        if samples.len() < min_samples {
+            return None;
+        }
+
+        Some(
+            column_set
+                .iter()
+                .map(|kind| Assessment {
+                    similarity: trimmed_mean(samples.iter().map(|v| kind.assess_value(v))),
+                    position: 0,
+                })
+                .collect(),
+        )
+    }
+
+    /// Classify every column of `rows` from sampled cell values alone, with no header involved.
+    ///
+    /// For each column, returns the best-scoring kind's index into `column_set` and its
+    /// confidence, or `None` where fewer than `min_samples` non-blank cells were sampled.
+    /// Returns an empty `Vec` if `column_set` is empty.
+    pub fn classify_columns_by_value<'v, Str, Row>(
+        column_set: &[impl ColumnKind],
+        rows: impl Iterator<Item = Row>,
+        min_samples: usize,
+    ) -> Vec<Option<(usize, f32)>>
+    where
+        Str: AsRef<str> + 'v,
+        Row: IntoIterator<Item = &'v Str>,
+    {
+        if column_set.is_empty() {
+            return Vec::new();
+        }
+
+        columns_of(rows)
+            .iter()
+            .map(|column| {
+                let values = column.iter().map(|cell| cell.as_ref());
+                Assessment::for_column_values(column_set, values, min_samples).map(|scored| {
+                    scored
+                        .iter()
+                        .enumerate()
+                        .max_by(|(_, a), (_, b)| a.similarity.total_cmp(&b.similarity))
+                        .map(|(kind_index, assessment)| (kind_index, assessment.similarity))
+                        .expect("column_set is non-empty")
+                })
+            })
+            .collect()
+    }
+}
+
+/// Transpose sampled `rows` into one `Vec` of cells per column.
+fn columns_of<'v, Str, Row>(rows: impl Iterator<Item = Row>) -> Vec<Vec<&'v Str>>
+where
+    Str: AsRef<str> + 'v,
+    Row: IntoIterator<Item = &'v Str>,
+{
+    let mut columns: Vec<Vec<&'v Str>> = Vec::new();
+    for row in rows {
+        for (position, cell) in row.into_iter().enumerate() {
+            if position >= columns.len() {
+                columns.resize_with(position + 1, Vec::new);
+            }
+            columns[position].push(cell);
+        }
+    }
+    columns
+}
+
+/// Trimmed mean of `scores`: discard roughly the lowest and highest 10% before averaging, so a
+/// handful of wildly wrong per-cell scores cannot dominate the aggregate confidence.
+fn trimmed_mean(scores: impl Iterator<Item = f32>) -> f32 {
+    let mut scores: Vec<f32> = scores.collect();
+    if scores.is_empty() {
+        return 0.0;
+    }
+    scores.sort_by(f32::total_cmp);
+
+    let trim = scores.len() / 10;
+    let kept = &scores[trim..scores.len() - trim];
+    let kept = if kept.is_empty() { &scores[..] } else { kept };
+
+    kept.iter().sum::<f32>() / kept.len() as f32
 }
 
 /// Configuration for predefined assessment algorithms.
@@ -85,6 +243,20 @@ pub struct SimpleAssessor {
     /// Dictionary is expected to contain 'a's instead of alphabets, and
     /// all 'a's should be surrounded by non-alphabets (or start/end of string).
     pub is_alpha_reduced: bool,
+
+    /// Whether to fold the input through Unicode canonical decomposition (NFD) and full
+    /// case folding before the rest of the normalization pipeline runs. Combining marks
+    /// (accents, diacritics) are dropped after decomposition, so e.g. `PRÉNOM` and `PRENOM`
+    /// become equivalent, and full case folding is used in place of [to_lowercase](str::to_lowercase)
+    /// so multi-character foldings (`ß`→`ss`) are handled correctly; `İ` becomes plain `i`.
+    /// Dictionary is expected to contain ASCII text, since folding removes all diacritics.
+    pub is_unicode_normalized: bool,
+
+    /// The similarity metric used to score a normalized value against each dictionary entry.
+    /// Defaults to [`Similarity::Jaro`], a good whole-string metric for short headers; fixed-shape
+    /// value templates (e.g. `"000-00-0000"`) are usually better served by
+    /// [`Similarity::FuzzySubsequence`].
+    pub similarity: Similarity,
 }
 
 impl Default for SimpleAssessor {
@@ -94,21 +266,31 @@ impl Default for SimpleAssessor {
             is_digit_sensitive: false,
             is_number_reduced: true,
             is_alpha_reduced: false,
+            is_unicode_normalized: false,
+            similarity: Similarity::default(),
         }
     }
 }
 
 impl SimpleAssessor {
-    pub fn with_dict<S: AsRef<str>>(
-        self,
-        value: impl AsRef<str>,
-        dict: impl Iterator<Item = S>,
-    ) -> f64 {
+    /// Default confidence threshold for [`ranked_with_dict`](Self::ranked_with_dict), matching
+    /// the `THRESHOLD` the test suite uses when reporting best header matches.
+    pub const DEFAULT_THRESHOLD: f64 = 0.7;
+
+    /// Normalize `value` according to this assessor's configuration, mirroring the
+    /// normalization expected of dictionary entries.
+    fn normalize(self, value: impl AsRef<str>) -> String {
+        let value = if self.is_unicode_normalized {
+            Cow::Owned(fold_unicode(value.as_ref(), self.is_case_sensitive))
+        } else {
+            Cow::Borrowed(value.as_ref())
+        };
         let value = if self.is_alpha_reduced {
             // Replace all continuous alphabets with 'a', expecting dictionary to present alphabets as 'a's
             Cow::Owned(reduce(value.as_ref(), char::is_alphabetic, 'a'))
-        } else if self.is_case_sensitive {
-            Cow::Borrowed(value.as_ref())
+        } else if self.is_case_sensitive || self.is_unicode_normalized {
+            // Already case-folded above if applicable; case_sensitive means leave case as-is.
+            value
         } else {
             Cow::Owned(value.as_ref().to_lowercase())
         };
@@ -127,32 +309,91 @@ impl SimpleAssessor {
         } else {
             value
         };
+        value.into_owned()
+    }
+
+    /// Assert, in debug builds, that `variant` already follows the normalization implied by
+    /// this assessor's configuration.
+    fn assert_dict_entry(self, variant: &str) {
+        if cfg!(debug_assertions) {
+            if self.is_unicode_normalized {
+                // Folding strips every diacritic, so dictionaries stay plain ASCII.
+                assert!(variant.is_ascii(), "dictionary entry `{variant}` is not ASCII");
+            }
+            if self.is_alpha_reduced {
+                assert_reduction(variant, char::is_alphabetic);
+            } else if self.is_case_sensitive {
+                assert!(variant.chars().all(|c| c.is_lowercase()));
+            }
+            if self.is_number_reduced {
+                assert_reduction(variant, |c| char::is_digit(c, 10));
+            } else if self.is_digit_sensitive {
+                assert!(variant.chars().all(|c| c == '0' || !c.is_digit(10)));
+            }
+        }
+    }
+
+    pub fn with_dict<S: AsRef<str>>(
+        self,
+        value: impl AsRef<str>,
+        dict: impl Iterator<Item = S>,
+    ) -> f64 {
+        let value = self.normalize(value);
 
         let mut max = 0.0;
         for variant in dict {
-            if cfg!(debug_assertions) {
-                if self.is_alpha_reduced {
-                    assert_reduction(variant.as_ref(), char::is_alphabetic);
-                } else if self.is_case_sensitive {
-                    assert!(variant.as_ref().chars().all(|c| c.is_lowercase()));
-                }
-                if self.is_number_reduced {
-                    assert_reduction(variant.as_ref(), |c| char::is_digit(c, 10));
-                } else if self.is_digit_sensitive {
-                    assert!(variant
-                        .as_ref()
-                        .chars()
-                        .all(|c| c == '0' || !c.is_digit(10)));
-                }
-            }
+            self.assert_dict_entry(variant.as_ref());
 
-            let similarity = strsim::jaro(&value, variant.as_ref());
+            let similarity = self.similarity.score(&value, variant.as_ref());
             if max < similarity {
                 max = similarity;
             }
         }
         max
     }
+
+    /// Like [`with_dict`](Self::with_dict), but instead of collapsing the dictionary down to a
+    /// single best score, returns every dictionary entry whose similarity exceeds `threshold`.
+    ///
+    /// Results are sorted ascending by similarity, so the best match is last. This lets callers
+    /// see and disambiguate between close runner-up candidates (e.g. a header scoring close
+    /// matches against two different column kinds) instead of only learning the winner.
+    pub fn ranked_with_dict<'d>(
+        self,
+        value: impl AsRef<str>,
+        dict: impl Iterator<Item = &'d str>,
+        threshold: f64,
+    ) -> Vec<(Cow<'d, str>, f64)> {
+        let value = self.normalize(value);
+
+        let mut ranked: Vec<(Cow<'d, str>, f64)> = dict
+            .filter_map(|variant| {
+                self.assert_dict_entry(variant);
+
+                let similarity = self.similarity.score(&value, variant);
+                (similarity > threshold).then_some((Cow::Borrowed(variant), similarity))
+            })
+            .collect();
+        ranked.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+        ranked
+    }
+}
+
+/// Canonically decompose `value` (NFD) and drop combining marks, so accented input normalizes
+/// to the same ASCII form as its unaccented dictionary counterpart. Also applies full Unicode
+/// case folding unless `case_sensitive`, in which case case is left untouched.
+fn fold_unicode(value: &str, case_sensitive: bool) -> String {
+    use unicode_normalization::UnicodeNormalization;
+
+    let decomposed: String = value
+        .nfd()
+        .filter(|c| !unicode_normalization::char::is_combining_mark(*c))
+        .collect();
+    if case_sensitive {
+        decomposed
+    } else {
+        caseless::default_case_fold_str(&decomposed)
+    }
 }
 
 fn reduce(value: &str, criteria: impl Fn(char) -> bool, with: char) -> String {
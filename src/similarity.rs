@@ -0,0 +1,68 @@
+//! String similarity metrics selectable on [`SimpleAssessor`](crate::SimpleAssessor), so header
+//! matching and value-shape matching can each use whichever metric fits their strings best.
+
+/// A pluggable string similarity strategy, scoring in `[0.0, 1.0]` where `1.0` is an exact match.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Similarity {
+    /// Whole-string Jaro similarity. Good general-purpose default for short headers.
+    #[default]
+    Jaro,
+
+    /// Levenshtein edit distance, normalized by the longer string's length.
+    Levenshtein,
+
+    /// In-order subsequence match: awards points per matched character, a bonus for runs of
+    /// consecutive matches, and penalizes gaps and leading skips. Better suited than Jaro (or
+    /// Jaro-Winkler) to fixed-shape value templates like `"000-00-0000"` or `"$0.0"`.
+    FuzzySubsequence,
+}
+
+impl Similarity {
+    /// Score the similarity of `a` to `b`, both assumed already normalized by the caller.
+    pub fn score(self, a: &str, b: &str) -> f64 {
+        match self {
+            Similarity::Jaro => strsim::jaro(a, b),
+            Similarity::Levenshtein => strsim::normalized_levenshtein(a, b),
+            Similarity::FuzzySubsequence => fuzzy_subsequence_score(a, b),
+        }
+    }
+}
+
+const MATCH_SCORE: f64 = 1.0;
+const CONSECUTIVE_BONUS: f64 = 0.5;
+const GAP_PENALTY: f64 = 0.05;
+const LEADING_SKIP_PENALTY: f64 = 0.05;
+
+/// Greedily align `pattern` as an in-order subsequence of `text`, scoring matched characters
+/// with a bonus for consecutive runs and a penalty per skipped character, then normalizing by
+/// the best score `pattern` could possibly achieve.
+fn fuzzy_subsequence_score(pattern: &str, text: &str) -> f64 {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    if pattern.is_empty() {
+        return if text.is_empty() { 1.0 } else { 0.0 };
+    }
+
+    let mut score = 0.0;
+    let mut cursor = 0usize;
+    let mut last_match: Option<usize> = None;
+
+    for &p in &pattern {
+        let Some(found_at) = text[cursor..].iter().position(|&t| t == p).map(|i| i + cursor) else {
+            continue;
+        };
+
+        score += MATCH_SCORE;
+        match last_match {
+            Some(prev) if found_at == prev + 1 => score += CONSECUTIVE_BONUS,
+            Some(prev) => score -= GAP_PENALTY * (found_at - prev - 1) as f64,
+            None => score -= LEADING_SKIP_PENALTY * found_at as f64,
+        }
+        last_match = Some(found_at);
+        cursor = found_at + 1;
+    }
+
+    // The first match can never earn the consecutive-run bonus, since it has no predecessor.
+    let max_score = MATCH_SCORE + (pattern.len() - 1) as f64 * (MATCH_SCORE + CONSECUTIVE_BONUS);
+    (score / max_score).clamp(0.0, 1.0)
+}
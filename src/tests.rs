@@ -155,6 +155,16 @@ impl TestColumnKind {
     }
 }
 
+impl ColumnKind for TestColumnKind {
+    fn assess_header(&self, header: &str) -> f32 {
+        SimpleAssessor::default().with_dict(header, self.header_dict().iter().copied()) as f32
+    }
+
+    fn assess_value(&self, value: &str) -> f32 {
+        self.value_assessor().with_dict(value, self.value_dict().iter().copied()) as f32
+    }
+}
+
 fn test_s(s: &[&str]) {
     let mut bests = Vec::with_capacity(TestColumnKind::iter().count());
     let assessor = SimpleAssessor::default();
@@ -202,3 +212,119 @@ fn s2() {
 fn s3() {
     test_s(S3);
 }
+
+#[test]
+fn ranked_with_dict_surfaces_runner_up_candidates() {
+    let dict = TestColumnKind::SubscriberName.header_dict();
+    let ranked = SimpleAssessor::default().ranked_with_dict(
+        "MEMBER NAME",
+        dict.iter().copied(),
+        SimpleAssessor::DEFAULT_THRESHOLD,
+    );
+
+    // "member name" is an exact match and must be the best (last) candidate, but "first name"
+    // and "subscriber name" are close enough to also clear the threshold - the whole point of
+    // ranked_with_dict is that those runner-ups aren't silently discarded.
+    assert_eq!(ranked.last().unwrap().0, "member name");
+    assert!(ranked.len() > 1, "expected runner-up candidates, got {ranked:?}");
+    assert!(ranked.windows(2).all(|w| w[0].1 <= w[1].1));
+}
+
+#[test]
+fn unicode_normalized_folds_diacritics() {
+    let assessor = SimpleAssessor {
+        is_unicode_normalized: true,
+        ..Default::default()
+    };
+
+    assert_eq!(assessor.with_dict("PRÉNOM", ["prenom"].into_iter()), 1.0);
+}
+
+#[test]
+fn unicode_normalized_respects_case_sensitive() {
+    // Unicode folding always case-folds unless the caller opted into case sensitivity - in
+    // which case differing case must still count against the similarity score.
+    let case_sensitive = SimpleAssessor {
+        is_case_sensitive: true,
+        is_unicode_normalized: true,
+        ..Default::default()
+    };
+    let case_insensitive = SimpleAssessor {
+        is_unicode_normalized: true,
+        ..Default::default()
+    };
+
+    assert_eq!(case_insensitive.with_dict("MemberName", ["membername"].into_iter()), 1.0);
+    assert!(case_sensitive.with_dict("MemberName", ["membername"].into_iter()) < 1.0);
+}
+
+#[test]
+fn assign_resolves_conflicting_header_matches() {
+    use TestColumnKind::*;
+
+    // All three kinds independently score highest against "MEMBER LEVEL AMOUNT" - a naive
+    // per-kind argmax would map all of them onto that one header. assign() must instead find
+    // the one-to-one pairing that maximizes total similarity across all three headers.
+    let kinds = [EmployeeAmount, DependentAmount, Premium];
+    let headers = [
+        "MEMBER LEVEL AMOUNT",
+        "TOTAL AMOUNT PER SUBSCRIBER",
+        "TOTAL AMOUNT PER CLASS",
+    ];
+
+    let matrix = Assessment::for_headers(&kinds, headers.iter());
+    let assignment = Assessment::assign(&matrix, 0.0);
+
+    assert_eq!(assignment.len(), 3);
+    let header_for = |kind_index: usize| {
+        assignment
+            .iter()
+            .find(|a| a.kind_index == kind_index)
+            .unwrap()
+            .header_position
+    };
+    assert_eq!(header_for(0), 1, "EmployeeAmount -> TOTAL AMOUNT PER SUBSCRIBER");
+    assert_eq!(header_for(1), 2, "DependentAmount -> TOTAL AMOUNT PER CLASS");
+    assert_eq!(header_for(2), 0, "Premium -> MEMBER LEVEL AMOUNT");
+}
+
+#[test]
+fn classify_columns_by_value_on_sparse_layout() {
+    use TestColumnKind::*;
+
+    // Mirrors the sparse S2 layout: most cells in each row are blank, so a column's kind has
+    // to be read off the handful of real values rather than any header.
+    let rows: [[&str; 2]; 6] = [
+        ["000-00-0001", ""],
+        ["", "A1234"],
+        ["000-00-0012", ""],
+        ["", "5678B"],
+        ["000-00-0123", ""],
+        ["", ""],
+    ];
+    let kinds = [Ssn, MemberId];
+
+    let classified = Assessment::classify_columns_by_value(&kinds, rows.iter(), 2);
+
+    assert_eq!(classified.len(), 2);
+    assert_eq!(classified[0].map(|(kind_index, _)| kind_index), Some(0), "column 0 is Ssn");
+    assert_eq!(classified[1].map(|(kind_index, _)| kind_index), Some(1), "column 1 is MemberId");
+}
+
+#[test]
+fn fuzzy_subsequence_scores_exact_matches_as_one() {
+    assert_eq!(Similarity::FuzzySubsequence.score("000-00-0000", "000-00-0000"), 1.0);
+    assert_eq!(Similarity::FuzzySubsequence.score("$0.0", "$0.0"), 1.0);
+}
+
+#[test]
+fn fuzzy_subsequence_tolerates_trailing_noise_better_than_jaro() {
+    // "usd"/"x" tacked onto an otherwise exact template shouldn't move FuzzySubsequence off
+    // 1.0, since the whole pattern is still matched in order - but it does cost Jaro, which
+    // scores the whole strings against each other with no notion of a trailing tail.
+    let pattern = "$0.0";
+    let text = "$0.0usd";
+
+    assert_eq!(Similarity::FuzzySubsequence.score(pattern, text), 1.0);
+    assert!(Similarity::Jaro.score(pattern, text) < 1.0);
+}